@@ -1,4 +1,10 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
 use tauri::{Emitter, Manager, PhysicalPosition};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconEvent};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tauri_plugin_store::StoreExt;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -13,6 +19,62 @@ struct PresetTime {
     label: String,
 }
 
+const SHORTCUTS_STORE: &str = "shortcuts.json";
+const SHORTCUTS_STORE_KEY: &str = "bindings";
+
+// Actions a global shortcut can be bound to; the frontend listens for these on "shortcut-action"
+const SHORTCUT_ACTIONS: [&str; 5] = [
+    "start_pause",
+    "reset",
+    "toggle_mode",
+    "cycle_preset",
+    "toggle_window",
+];
+
+// Maps registered (parsed) shortcuts to the action they trigger. Keyed on the
+// parsed `Shortcut` rather than the raw input string: the plugin hands fired
+// shortcuts back to the handler as `Shortcut` values in their canonical form
+// (e.g. "CommandOrControl+Shift+T" parses to a platform-resolved `KeyT` +
+// modifiers), so comparing by string round-trip would never match.
+struct ShortcutBindings(Mutex<HashMap<Shortcut, String>>);
+
+// Persist bindings as their canonical string form so they can be re-parsed on restore
+fn save_shortcut_bindings(app: &tauri::AppHandle, bindings: &HashMap<Shortcut, String>) -> Result<(), String> {
+    let serializable: HashMap<String, String> = bindings
+        .iter()
+        .map(|(shortcut, action)| (shortcut.to_string(), action.clone()))
+        .collect();
+
+    let store = app.store(SHORTCUTS_STORE).map_err(|e| e.to_string())?;
+    store.set(SHORTCUTS_STORE_KEY, serde_json::json!(serializable));
+    store.save().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+// Show the main window if hidden (focusing and raising it), or hide it if visible,
+// then refresh the tray's Show/Hide label to match. Shared by the tray left-click
+// handler and the "toggle" menu item so both paths stay in sync.
+fn toggle_main_window(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let is_visible = window.is_visible().unwrap_or(false);
+        if is_visible {
+            let _ = window.hide();
+        } else {
+            let _ = window.show();
+            let _ = window.unminimize();
+            let _ = window.set_focus();
+            let _ = window.set_always_on_top(true);
+        }
+
+        let app_handle = app.clone();
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = update_tray_toggle_label(app_handle).await {
+                eprintln!("Failed to update tray toggle label: {}", e);
+            }
+        });
+    }
+}
+
 // Get screen dimensions and calculate position ON TOP of taskbar
 #[tauri::command]
 async fn get_default_position(window: tauri::WebviewWindow) -> Result<WindowPosition, String> {
@@ -58,10 +120,166 @@ async fn get_window_position(window: tauri::WebviewWindow) -> Result<WindowPosit
     })
 }
 
-// Register global shortcut
+// Update the tray's Show/Hide item label in place so it reflects the window's actual visibility
+#[tauri::command]
+async fn update_tray_toggle_label(app: tauri::AppHandle) -> Result<(), String> {
+    let tray = app.tray_by_id("main-tray").ok_or("Tray not found")?;
+    let menu = tray.menu().ok_or("Tray menu not found")?;
+
+    let is_visible = app
+        .get_webview_window("main")
+        .and_then(|window| window.is_visible().ok())
+        .unwrap_or(false);
+    let label = if is_visible { "Hide Timer" } else { "Show Timer" };
+
+    if let Some(item) = menu.get("toggle") {
+        if let Some(menu_item) = item.as_menuitem() {
+            menu_item.set_text(label).map_err(|e| e.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+// Register a global shortcut and bind it to one of the known actions
+#[tauri::command]
+async fn register_shortcut(app: tauri::AppHandle, shortcut: String, action: String) -> Result<(), String> {
+    if !SHORTCUT_ACTIONS.contains(&action.as_str()) {
+        return Err(format!("Unknown shortcut action: {}", action));
+    }
+
+    let parsed: Shortcut = shortcut
+        .parse()
+        .map_err(|e| format!("Invalid shortcut \"{}\": {}", shortcut, e))?;
+
+    app.global_shortcut()
+        .register(parsed)
+        .map_err(|e| format!("Failed to register shortcut: {}", e))?;
+
+    let bindings = app.state::<ShortcutBindings>();
+    let snapshot = {
+        let mut map = bindings.0.lock().unwrap();
+        map.insert(parsed, action);
+        map.clone()
+    };
+    save_shortcut_bindings(&app, &snapshot)?;
+
+    Ok(())
+}
+
+// Unregister a previously bound global shortcut (e.g. when the user rebinds it in settings)
 #[tauri::command]
-async fn register_shortcut(_app: tauri::AppHandle, _shortcut: String, _action: String) -> Result<(), String> {
-    // This will be handled by the global shortcut plugin on the frontend side
+async fn unregister_shortcut(app: tauri::AppHandle, shortcut: String) -> Result<(), String> {
+    let parsed: Shortcut = shortcut
+        .parse()
+        .map_err(|e| format!("Invalid shortcut \"{}\": {}", shortcut, e))?;
+
+    app.global_shortcut()
+        .unregister(parsed)
+        .map_err(|e| format!("Failed to unregister shortcut: {}", e))?;
+
+    let bindings = app.state::<ShortcutBindings>();
+    let snapshot = {
+        let mut map = bindings.0.lock().unwrap();
+        map.remove(&parsed);
+        map.clone()
+    };
+    save_shortcut_bindings(&app, &snapshot)?;
+
+    Ok(())
+}
+
+// Flips each time `render_progress_icon` draws an expired frame, so repeated
+// once-a-second calls from the frontend blink the ring on and off.
+static TRAY_FLASH_ON: AtomicBool = AtomicBool::new(true);
+
+// Render an in-memory RGBA progress ring icon filled to `fraction` (0.0 - 1.0).
+// Once the timer has expired the ring flashes red, alternating between a full
+// ring and a blank icon on successive calls (see `TRAY_FLASH_ON`).
+fn render_progress_icon(fraction: f32) -> tauri::image::Image<'static> {
+    const SIZE: u32 = 32;
+    const THICKNESS: f32 = 4.0;
+    let radius = SIZE as f32 / 2.0;
+    let center = radius;
+    let fraction = fraction.clamp(0.0, 1.0);
+    let expired = fraction >= 1.0;
+    let color: [u8; 3] = if expired { [255, 80, 80] } else { [90, 160, 250] };
+    let sweep = fraction * std::f32::consts::TAU;
+
+    let mut rgba = vec![0u8; (SIZE * SIZE * 4) as usize];
+
+    // Blank frame for this blink cycle: skip drawing and return the empty buffer
+    if expired && !TRAY_FLASH_ON.fetch_xor(true, Ordering::Relaxed) {
+        return tauri::image::Image::new_owned(rgba, SIZE, SIZE);
+    }
+
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            let dx = x as f32 + 0.5 - center;
+            let dy = y as f32 + 0.5 - center;
+            let dist = (dx * dx + dy * dy).sqrt();
+            if dist <= radius - THICKNESS || dist > radius {
+                continue;
+            }
+
+            let mut angle = dy.atan2(dx) + std::f32::consts::FRAC_PI_2;
+            if angle < 0.0 {
+                angle += std::f32::consts::TAU;
+            }
+            if angle <= sweep {
+                let idx = ((y * SIZE + x) * 4) as usize;
+                rgba[idx] = color[0];
+                rgba[idx + 1] = color[1];
+                rgba[idx + 2] = color[2];
+                rgba[idx + 3] = 255;
+            }
+        }
+    }
+
+    tauri::image::Image::new_owned(rgba, SIZE, SIZE)
+}
+
+// Repaint the tray icon with a progress ring so remaining time is glanceable while the window is hidden
+#[tauri::command]
+async fn set_tray_progress(app: tauri::AppHandle, fraction: f32, label: String) -> Result<(), String> {
+    let tray = app.tray_by_id("main-tray").ok_or("Tray not found")?;
+
+    tray.set_icon(Some(render_progress_icon(fraction))).map_err(|e| e.to_string())?;
+    tray.set_tooltip(Some(label)).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+// Restore the default tray icon once the timer is stopped
+#[tauri::command]
+async fn reset_tray_icon(app: tauri::AppHandle) -> Result<(), String> {
+    let tray = app.tray_by_id("main-tray").ok_or("Tray not found")?;
+    let icon = app.default_window_icon().ok_or("No default window icon")?.clone();
+
+    tray.set_icon(Some(icon)).map_err(|e| e.to_string())?;
+    tray.set_tooltip(Some("timebar")).map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+// Switch the Dock icon/app-switcher entry on or off at runtime (macOS only)
+#[tauri::command]
+async fn set_dock_visible(app: tauri::AppHandle, visible: bool) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let policy = if visible {
+            tauri::ActivationPolicy::Regular
+        } else {
+            tauri::ActivationPolicy::Accessory
+        };
+        app.set_activation_policy(policy);
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = (app, visible);
+    }
+
     Ok(())
 }
 
@@ -123,8 +341,14 @@ async fn rebuild_tray_menu(app: tauri::AppHandle, presets: Vec<PresetTime>) -> R
         .map_err(|e| e.to_string())?;
     
     // Build menu with dynamic presets
+    let is_visible = app
+        .get_webview_window("main")
+        .and_then(|window| window.is_visible().ok())
+        .unwrap_or(false);
+    let toggle_label = if is_visible { "Hide Timer" } else { "Show Timer" };
+
     let mut menu_builder = tauri::menu::MenuBuilder::new(&app)
-        .text("show", "Show Timer")
+        .text("toggle", toggle_label)
         .separator();
     
     // Add preset menu items dynamically
@@ -149,8 +373,9 @@ async fn rebuild_tray_menu(app: tauri::AppHandle, presets: Vec<PresetTime>) -> R
     
     // Update the tray menu
     tray.set_menu(Some(new_menu)).map_err(|e| e.to_string())?;
-    
+
     println!("Tray menu rebuilt successfully");
+    update_tray_toggle_label(app).await?;
     Ok(())
 }
 
@@ -159,8 +384,84 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_store::Builder::new().build())
-        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(|app, shortcut, event| {
+                    if event.state != ShortcutState::Pressed {
+                        return;
+                    }
+
+                    let bindings = app.state::<ShortcutBindings>();
+                    let action = bindings.0.lock().unwrap().get(shortcut).cloned();
+
+                    if let Some(action) = action {
+                        if let Some(window) = app.get_webview_window("main") {
+                            if let Err(e) = window.emit("shortcut-action", action) {
+                                println!("Failed to emit shortcut-action: {:?}", e);
+                            }
+                        }
+                    }
+                })
+                .build(),
+        )
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                if window.label() == "main" {
+                    let _ = window.hide();
+                    api.prevent_close();
+
+                    let app_handle = window.app_handle().clone();
+                    tauri::async_runtime::spawn(async move {
+                        if let Err(e) = update_tray_toggle_label(app_handle).await {
+                            eprintln!("Failed to update tray toggle label: {}", e);
+                        }
+                    });
+                }
+            }
+        })
         .setup(|app| {
+            // Run as a pure menu-bar utility on macOS: no Dock icon, no app-switcher entry
+            #[cfg(target_os = "macos")]
+            app.set_activation_policy(tauri::ActivationPolicy::Accessory);
+
+            app.manage(ShortcutBindings(Mutex::new(HashMap::new())));
+
+            // Restore and re-register shortcuts persisted from a previous run. Entries that fail
+            // to parse or register (e.g. now conflicting with another app) are dropped from the
+            // snapshot we persist back, so a stale binding doesn't retry-and-fail on every launch.
+            if let Ok(store) = app.store(SHORTCUTS_STORE) {
+                if let Some(saved) = store.get(SHORTCUTS_STORE_KEY) {
+                    if let Ok(saved) = serde_json::from_value::<HashMap<String, String>>(saved) {
+                        let bindings = app.state::<ShortcutBindings>();
+                        let had_saved = !saved.is_empty();
+                        {
+                            let mut map = bindings.0.lock().unwrap();
+                            for (shortcut_str, action) in saved {
+                                let parsed: Shortcut = match shortcut_str.parse() {
+                                    Ok(parsed) => parsed,
+                                    Err(e) => {
+                                        println!("Failed to parse persisted shortcut {}: {}", shortcut_str, e);
+                                        continue;
+                                    }
+                                };
+                                if let Err(e) = app.global_shortcut().register(parsed) {
+                                    println!("Failed to restore shortcut {}: {}", shortcut_str, e);
+                                    continue;
+                                }
+                                map.insert(parsed, action);
+                            }
+                        }
+
+                        if had_saved {
+                            let snapshot = bindings.0.lock().unwrap().clone();
+                            if let Err(e) = save_shortcut_bindings(app.handle(), &snapshot) {
+                                println!("Failed to prune stale shortcut bindings: {}", e);
+                            }
+                        }
+                    }
+                }
+            }
+
             // Create theme submenu
             let theme_submenu = tauri::menu::SubmenuBuilder::new(app, "Themes")
                 .text("theme_blue", "Ocean Blue")
@@ -179,7 +480,7 @@ pub fn run() {
             // Note: Menu labels are static, but they trigger preset indices
             // Users can customize what each preset does via the settings window
             let tray_menu = tauri::menu::MenuBuilder::new(app)
-                .text("show", "Show Timer")
+                .text("toggle", "Show Timer")
                 .separator()
                 .text("set_3min", "Preset 1 (3 min)")
                 .text("set_5min", "Preset 2 (5 min)")
@@ -197,6 +498,16 @@ pub fn run() {
             let _tray = tauri::tray::TrayIconBuilder::with_id("main-tray")
                 .icon(app.default_window_icon().unwrap().clone())
                 .menu(&tray_menu)
+                .on_tray_icon_event(|tray, event| {
+                    if let TrayIconEvent::Click {
+                        button: MouseButton::Left,
+                        button_state: MouseButtonState::Up,
+                        ..
+                    } = event
+                    {
+                        toggle_main_window(tray.app_handle());
+                    }
+                })
                 .on_menu_event(move |app, event| {
                     println!("Tray menu event: {}", event.id().as_ref());
                     let event_id = event.id().as_ref();
@@ -215,14 +526,9 @@ pub fn run() {
                     }
                     
                     match event_id {
-                        "show" => {
-                            println!("Show clicked");
-                            if let Some(window) = app.get_webview_window("main") {
-                                let _ = window.show();
-                                let _ = window.unminimize();
-                                let _ = window.set_focus();
-                                let _ = window.set_always_on_top(true);
-                            }
+                        "toggle" => {
+                            println!("Toggle clicked");
+                            toggle_main_window(app);
                         }
                         "set_3min" => {
                             println!("Preset 1 clicked");
@@ -369,8 +675,13 @@ pub fn run() {
             set_window_position,
             get_window_position,
             register_shortcut,
+            unregister_shortcut,
             open_preset_settings,
-            rebuild_tray_menu
+            rebuild_tray_menu,
+            update_tray_toggle_label,
+            set_tray_progress,
+            reset_tray_icon,
+            set_dock_visible
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");